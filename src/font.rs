@@ -0,0 +1,110 @@
+//! Embedded 8×16 monospaced bitmap font covering printable ASCII
+//! (`0x20..=0x7e`). Glyphs are stored row-major, one byte per row with
+//! the most significant bit as the leftmost pixel.
+
+/// Width of each glyph cell, in pixels.
+pub const GLYPH_W: usize = 8;
+/// Height of each glyph cell, in pixels.
+pub const GLYPH_H: usize = 16;
+
+/// First ASCII codepoint present in [`FONT`].
+pub const FIRST: u8 = 0x20;
+
+/// Glyph bitmaps for `0x20..=0x7e`, indexed by `ch - FIRST`.
+pub const FONT: [[u8; GLYPH_H]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 'space'
+    [0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x18, 0x18, 0x00, 0x00], // '!'
+    [0x6c, 0x6c, 0x6c, 0x6c, 0x6c, 0x6c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x6c, 0x6c, 0x6c, 0x6c, 0xfe, 0xfe, 0x6c, 0x6c, 0xfe, 0xfe, 0x6c, 0x6c, 0x6c, 0x6c, 0x00, 0x00], // '#'
+    [0x18, 0x18, 0x7c, 0x7c, 0xc0, 0xc0, 0x78, 0x78, 0x0c, 0x0c, 0xf8, 0xf8, 0x18, 0x18, 0x00, 0x00], // '$'
+    [0xc6, 0xc6, 0xcc, 0xcc, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0xc6, 0xc6, 0x0c, 0x0c, 0x00, 0x00], // '%'
+    [0x70, 0x70, 0xd8, 0xd8, 0x70, 0x70, 0xec, 0xec, 0xd8, 0xd8, 0xdc, 0xdc, 0x7a, 0x7a, 0x00, 0x00], // '&'
+    [0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '''
+    [0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x18, 0x18, 0x0c, 0x0c, 0x00, 0x00], // '('
+    [0x30, 0x30, 0x18, 0x18, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x00, 0x00], // ')'
+    [0x00, 0x00, 0x28, 0x28, 0x10, 0x10, 0x7c, 0x7c, 0x10, 0x10, 0x28, 0x28, 0x00, 0x00, 0x00, 0x00], // '*'
+    [0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x7e, 0x7e, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x30, 0x30], // ','
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x7e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00], // '.'
+    [0x03, 0x03, 0x06, 0x06, 0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0xc0, 0xc0, 0x00, 0x00], // '/'
+    [0x78, 0x78, 0xcc, 0xcc, 0xdc, 0xdc, 0xd6, 0xd6, 0xec, 0xec, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // '0'
+    [0x18, 0x18, 0x38, 0x38, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x7e, 0x7e, 0x00, 0x00], // '1'
+    [0x78, 0x78, 0xcc, 0xcc, 0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0xfc, 0xfc, 0x00, 0x00], // '2'
+    [0x78, 0x78, 0xcc, 0xcc, 0x0c, 0x0c, 0x38, 0x38, 0x0c, 0x0c, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // '3'
+    [0x1c, 0x1c, 0x3c, 0x3c, 0x6c, 0x6c, 0xcc, 0xcc, 0xfe, 0xfe, 0x0c, 0x0c, 0x0c, 0x0c, 0x00, 0x00], // '4'
+    [0xfc, 0xfc, 0xc0, 0xc0, 0xf8, 0xf8, 0x0c, 0x0c, 0x0c, 0x0c, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // '5'
+    [0x38, 0x38, 0x60, 0x60, 0xc0, 0xc0, 0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // '6'
+    [0xfc, 0xfc, 0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00], // '7'
+    [0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // '8'
+    [0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0x7c, 0x7c, 0x0c, 0x0c, 0x18, 0x18, 0x70, 0x70, 0x00, 0x00], // '9'
+    [0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00], // ':'
+    [0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x18, 0x18, 0x18, 0x18, 0x30, 0x30, 0x00, 0x00], // ';'
+    [0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0x30, 0x30, 0x18, 0x18, 0x0c, 0x0c, 0x00, 0x00], // '<'
+    [0x00, 0x00, 0x00, 0x00, 0x7e, 0x7e, 0x00, 0x00, 0x7e, 0x7e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '='
+    [0x60, 0x60, 0x30, 0x30, 0x18, 0x18, 0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0x00, 0x00], // '>'
+    [0x78, 0x78, 0xcc, 0xcc, 0x0c, 0x0c, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x18, 0x18, 0x00, 0x00], // '?'
+    [0x78, 0x78, 0xcc, 0xcc, 0xdc, 0xdc, 0xdc, 0xdc, 0xdc, 0xdc, 0xc0, 0xc0, 0x7c, 0x7c, 0x00, 0x00], // '@'
+    [0x30, 0x30, 0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0xfc, 0xfc, 0xcc, 0xcc, 0xcc, 0xcc, 0x00, 0x00], // 'A'
+    [0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xf8, 0xf8, 0x00, 0x00], // 'B'
+    [0x78, 0x78, 0xcc, 0xcc, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'C'
+    [0xf0, 0xf0, 0xd8, 0xd8, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xd8, 0xd8, 0xf0, 0xf0, 0x00, 0x00], // 'D'
+    [0xfc, 0xfc, 0xc0, 0xc0, 0xc0, 0xc0, 0xf8, 0xf8, 0xc0, 0xc0, 0xc0, 0xc0, 0xfc, 0xfc, 0x00, 0x00], // 'E'
+    [0xfc, 0xfc, 0xc0, 0xc0, 0xc0, 0xc0, 0xf8, 0xf8, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0x00, 0x00], // 'F'
+    [0x78, 0x78, 0xcc, 0xcc, 0xc0, 0xc0, 0xdc, 0xdc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'G'
+    [0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xfc, 0xfc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x00, 0x00], // 'H'
+    [0x78, 0x78, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x78, 0x78, 0x00, 0x00], // 'I'
+    [0x1c, 0x1c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'J'
+    [0xcc, 0xcc, 0xd8, 0xd8, 0xf0, 0xf0, 0xe0, 0xe0, 0xf0, 0xf0, 0xd8, 0xd8, 0xcc, 0xcc, 0x00, 0x00], // 'K'
+    [0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xfc, 0xfc, 0x00, 0x00], // 'L'
+    [0xc6, 0xc6, 0xee, 0xee, 0xfe, 0xfe, 0xd6, 0xd6, 0xc6, 0xc6, 0xc6, 0xc6, 0xc6, 0xc6, 0x00, 0x00], // 'M'
+    [0xcc, 0xcc, 0xec, 0xec, 0xf6, 0xf6, 0xdc, 0xdc, 0xce, 0xce, 0xcc, 0xcc, 0xcc, 0xcc, 0x00, 0x00], // 'N'
+    [0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'O'
+    [0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xf8, 0xf8, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0x00, 0x00], // 'P'
+    [0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xdc, 0xdc, 0xd8, 0xd8, 0x7a, 0x7a, 0x00, 0x00], // 'Q'
+    [0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xf8, 0xf8, 0xf0, 0xf0, 0xd8, 0xd8, 0xcc, 0xcc, 0x00, 0x00], // 'R'
+    [0x7c, 0x7c, 0xc0, 0xc0, 0xc0, 0xc0, 0x78, 0x78, 0x0c, 0x0c, 0x0c, 0x0c, 0xf8, 0xf8, 0x00, 0x00], // 'S'
+    [0xfc, 0xfc, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00], // 'T'
+    [0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'U'
+    [0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x30, 0x30, 0x00, 0x00], // 'V'
+    [0xc6, 0xc6, 0xc6, 0xc6, 0xc6, 0xc6, 0xd6, 0xd6, 0xfe, 0xfe, 0xee, 0xee, 0xc6, 0xc6, 0x00, 0x00], // 'W'
+    [0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x30, 0x30, 0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0x00, 0x00], // 'X'
+    [0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00], // 'Y'
+    [0xfc, 0xfc, 0x0c, 0x0c, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0xc0, 0xc0, 0xfc, 0xfc, 0x00, 0x00], // 'Z'
+    [0x78, 0x78, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x78, 0x78, 0x00, 0x00], // '['
+    [0xc0, 0xc0, 0x60, 0x60, 0x30, 0x30, 0x18, 0x18, 0x0c, 0x0c, 0x06, 0x06, 0x03, 0x03, 0x00, 0x00], // '\\'
+    [0x78, 0x78, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x78, 0x78, 0x00, 0x00], // ']'
+    [0x10, 0x10, 0x38, 0x38, 0x6c, 0x6c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfc, 0xfc], // '_'
+    [0x30, 0x30, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x00, 0x00, 0x78, 0x78, 0x0c, 0x0c, 0x7c, 0x7c, 0xcc, 0xcc, 0x7c, 0x7c, 0x00, 0x00], // 'a'
+    [0xc0, 0xc0, 0xc0, 0xc0, 0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xf8, 0xf8, 0x00, 0x00], // 'b'
+    [0x00, 0x00, 0x00, 0x00, 0x78, 0x78, 0xcc, 0xcc, 0xc0, 0xc0, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'c'
+    [0x0c, 0x0c, 0x0c, 0x0c, 0x7c, 0x7c, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x7c, 0x7c, 0x00, 0x00], // 'd'
+    [0x00, 0x00, 0x00, 0x00, 0x78, 0x78, 0xcc, 0xcc, 0xfc, 0xfc, 0xc0, 0xc0, 0x78, 0x78, 0x00, 0x00], // 'e'
+    [0x38, 0x38, 0x6c, 0x6c, 0x60, 0x60, 0xf0, 0xf0, 0x60, 0x60, 0x60, 0x60, 0x60, 0x60, 0x00, 0x00], // 'f'
+    [0x00, 0x00, 0x00, 0x00, 0x7c, 0x7c, 0xcc, 0xcc, 0xcc, 0xcc, 0x7c, 0x7c, 0x0c, 0x0c, 0x78, 0x78], // 'g'
+    [0xc0, 0xc0, 0xc0, 0xc0, 0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x00, 0x00], // 'h'
+    [0x18, 0x18, 0x00, 0x00, 0x38, 0x38, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x7e, 0x7e, 0x00, 0x00], // 'i'
+    [0x0c, 0x0c, 0x00, 0x00, 0x1c, 0x1c, 0x0c, 0x0c, 0x0c, 0x0c, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78], // 'j'
+    [0xc0, 0xc0, 0xc0, 0xc0, 0xcc, 0xcc, 0xd8, 0xd8, 0xf0, 0xf0, 0xd8, 0xd8, 0xcc, 0xcc, 0x00, 0x00], // 'k'
+    [0x38, 0x38, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x7e, 0x7e, 0x00, 0x00], // 'l'
+    [0x00, 0x00, 0x00, 0x00, 0xd8, 0xd8, 0xfe, 0xfe, 0xd6, 0xd6, 0xd6, 0xd6, 0xc6, 0xc6, 0x00, 0x00], // 'm'
+    [0x00, 0x00, 0x00, 0x00, 0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x00, 0x00], // 'n'
+    [0x00, 0x00, 0x00, 0x00, 0x78, 0x78, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x00, 0x00], // 'o'
+    [0x00, 0x00, 0x00, 0x00, 0xf8, 0xf8, 0xcc, 0xcc, 0xcc, 0xcc, 0xf8, 0xf8, 0xc0, 0xc0, 0xc0, 0xc0], // 'p'
+    [0x00, 0x00, 0x00, 0x00, 0x7c, 0x7c, 0xcc, 0xcc, 0xcc, 0xcc, 0x7c, 0x7c, 0x0c, 0x0c, 0x0c, 0x0c], // 'q'
+    [0x00, 0x00, 0x00, 0x00, 0xdc, 0xdc, 0xec, 0xec, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0x00, 0x00], // 'r'
+    [0x00, 0x00, 0x00, 0x00, 0x7c, 0x7c, 0xc0, 0xc0, 0x78, 0x78, 0x0c, 0x0c, 0xf8, 0xf8, 0x00, 0x00], // 's'
+    [0x60, 0x60, 0x60, 0x60, 0xf0, 0xf0, 0x60, 0x60, 0x60, 0x60, 0x6c, 0x6c, 0x38, 0x38, 0x00, 0x00], // 't'
+    [0x00, 0x00, 0x00, 0x00, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x7c, 0x7c, 0x00, 0x00], // 'u'
+    [0x00, 0x00, 0x00, 0x00, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x78, 0x78, 0x30, 0x30, 0x00, 0x00], // 'v'
+    [0x00, 0x00, 0x00, 0x00, 0xc6, 0xc6, 0xd6, 0xd6, 0xd6, 0xd6, 0xfe, 0xfe, 0x6c, 0x6c, 0x00, 0x00], // 'w'
+    [0x00, 0x00, 0x00, 0x00, 0xcc, 0xcc, 0x78, 0x78, 0x30, 0x30, 0x78, 0x78, 0xcc, 0xcc, 0x00, 0x00], // 'x'
+    [0x00, 0x00, 0x00, 0x00, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x7c, 0x7c, 0x0c, 0x0c, 0x78, 0x78], // 'y'
+    [0x00, 0x00, 0x00, 0x00, 0xfc, 0xfc, 0x18, 0x18, 0x30, 0x30, 0x60, 0x60, 0xfc, 0xfc, 0x00, 0x00], // 'z'
+    [0x1c, 0x1c, 0x30, 0x30, 0x30, 0x30, 0x60, 0x60, 0x30, 0x30, 0x30, 0x30, 0x1c, 0x1c, 0x00, 0x00], // '{'
+    [0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x00, 0x00], // '|'
+    [0xe0, 0xe0, 0x30, 0x30, 0x30, 0x30, 0x18, 0x18, 0x30, 0x30, 0x30, 0x30, 0xe0, 0xe0, 0x00, 0x00], // '}'
+    [0x00, 0x00, 0x00, 0x00, 0x62, 0x62, 0x96, 0x96, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '~'
+];
@@ -1,11 +1,11 @@
-use std::{path::PathBuf, time::Duration};
+use std::{io::Read, path::PathBuf, time::Duration};
 
 use anyhow::{anyhow, Context};
 use btleplug::{
     api::{Central, CentralEvent, Manager as _, Peripheral as _, ScanFilter, WriteType},
     platform::{Adapter, Manager, Peripheral},
 };
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use futures::stream::StreamExt;
 use image::{
     imageops::FilterType::{self, Gaussian},
@@ -14,8 +14,9 @@ use image::{
 use log::{debug, info};
 use printer::PrintDriver;
 
-use crate::v5g::{CmdPacket, CommandId, PrintMode};
+use crate::v5g::{Align, DeviceProfile, Dither, Model, PrintSettings, TextSettings};
 
+pub mod font;
 pub mod printer;
 pub mod v5g;
 
@@ -61,11 +62,92 @@ async fn locate_device(central: &Adapter, search_name: &str) -> anyhow::Result<O
 #[derive(Debug, Parser)]
 #[command(version)]
 struct Args {
-    #[arg(short, long, default_value = "MX10")]
+    #[arg(short, long, default_value = "MX10", global = true)]
     search_name: String,
 
-    #[arg(required = true)]
-    images: Vec<PathBuf>,
+    /// cat-printer family to target; selects model-specific quirks
+    #[arg(short, long, value_enum, default_value_t = ModelArg::Mx10, global = true)]
+    model: ModelArg,
+
+    #[command(subcommand)]
+    cmd: Command,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum ModelArg {
+    Mx10,
+    Mx06,
+    Gb01,
+    Gb02,
+}
+
+impl From<ModelArg> for Model {
+    fn from(m: ModelArg) -> Self {
+        match m {
+            ModelArg::Mx10 => Model::Mx10,
+            ModelArg::Mx06 => Model::Mx06,
+            ModelArg::Gb01 => Model::Gb01,
+            ModelArg::Gb02 => Model::Gb02,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum DitherArg {
+    Threshold,
+    Bayer4x4,
+    FloydSteinberg,
+}
+
+impl From<DitherArg> for Dither {
+    fn from(d: DitherArg) -> Self {
+        match d {
+            DitherArg::Threshold => Dither::Threshold,
+            DitherArg::Bayer4x4 => Dither::Bayer4x4,
+            DitherArg::FloydSteinberg => Dither::FloydSteinberg,
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print one or more images.
+    Image {
+        #[arg(required = true)]
+        images: Vec<PathBuf>,
+        /// burn energy per dot
+        #[arg(long, default_value_t = 10_000)]
+        energy: u16,
+        /// print speed
+        #[arg(long, default_value_t = 10)]
+        speed: u8,
+        /// quality level, 1..=5
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(1..=5))]
+        quality: u8,
+        /// number of paper-advance commands emitted after the image
+        #[arg(long, default_value_t = 2)]
+        feeds: usize,
+        /// dithering used to reduce the image to 1-bit
+        #[arg(long, value_enum, default_value_t = DitherArg::FloydSteinberg)]
+        dither: DitherArg,
+        /// max outstanding bytes before polling the head
+        #[arg(long, default_value_t = 2048)]
+        max_inflight_bytes: usize,
+        /// status-poll cadence while streaming, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        poll_interval_ms: u64,
+    },
+    /// Print UTF-8 text read from `--text` or, if absent, stdin.
+    Text {
+        #[arg(long)]
+        text: Option<String>,
+        /// integer pixel scale applied to the base 8×16 font
+        #[arg(long, default_value_t = 1)]
+        scale: u32,
+        /// center each line instead of left-aligning
+        #[arg(long)]
+        center: bool,
+    },
 }
 
 #[tokio::main]
@@ -99,45 +181,96 @@ async fn main() -> anyhow::Result<()> {
     info!("discovering services and characteristics...");
     peripheral.discover_services().await?;
 
+    let profile = DeviceProfile::for_model(args.model.into());
+
     let characteristics = peripheral.characteristics();
     // info!("  found characteristics: {:#?}", characteristics);
     let char_cmd_no_resp = characteristics
         .iter()
-        .find(|c| c.uuid == v5g::CHAR_UUID_WRITE_NO_RESP)
+        .find(|c| c.uuid == profile.char_write)
         .ok_or(anyhow!("couldn't find WRITE_NO_RESP characteristic"))?;
     info!("found char_cmd_no_resp = {:?}", char_cmd_no_resp);
 
     let char_notify = characteristics
         .iter()
-        .find(|c| c.uuid == v5g::CHAR_UUID_NOTIFY)
+        .find(|c| c.uuid == profile.char_notify)
         .ok_or(anyhow!("couldn't find NOTIFY characteristic"))?;
     info!("found char_notify = {:?}", char_notify);
 
     peripheral.subscribe(&char_notify).await?;
+    // single NOTIFY consumer: log everything and publish decoded statuses so the
+    // driver's pre-print/flow-control polls don't race us for the reply
+    let (status_tx, status_rx) = tokio::sync::watch::channel(None);
     let mut notify_stream = peripheral.notifications().await?;
     tokio::spawn(async move {
         while let Some(dat) = notify_stream.next().await {
-            info!(
-                "NOTIFY [{:?}]: {:?} => {:?}",
-                dat.uuid,
-                dat.value,
-                v5g::NotifyResponse::parse(&dat.value)
-            );
+            let parsed = v5g::NotifyResponse::parse(&dat.value);
+            info!("NOTIFY [{:?}]: {:?} => {:?}", dat.uuid, dat.value, parsed);
+            if let Ok(v5g::NotifyResponse::Status(status)) = parsed {
+                let _ = status_tx.send(Some(status));
+            }
         }
     });
 
-    for imgpath in args.images {
-        let img = ImageReader::open(imgpath)?.decode()?;
-        let img = img.resize(v5g::HORIZ_RESOLUTION, u32::MAX, FilterType::Gaussian);
-        let img = img.grayscale().to_luma8();
+    let printer = v5g::Driver {
+        peripheral: &peripheral,
+        char_cmd_no_resp,
+        char_notify,
+        profile,
+        status_rx,
+    };
+
+    match args.cmd {
+        Command::Image {
+            images,
+            energy,
+            speed,
+            quality,
+            feeds,
+            dither,
+            max_inflight_bytes,
+            poll_interval_ms,
+        } => {
+            let settings = PrintSettings::default()
+                .energy(energy)
+                .print_speed(speed)
+                .quality(quality)
+                .feeds_after(feeds)
+                .dither(dither.into())
+                .max_inflight_bytes(max_inflight_bytes)
+                .poll_interval(Duration::from_millis(poll_interval_ms));
 
-        let printer = v5g::Driver {
-            peripheral: &peripheral,
-            char_cmd_no_resp,
-            char_notify,
-        };
+            for imgpath in images {
+                let img = ImageReader::open(imgpath)?.decode()?;
+                let img = img.resize(v5g::HORIZ_RESOLUTION, u32::MAX, FilterType::Gaussian);
+                let img = img.grayscale().to_luma8();
 
-        printer.print(img, Default::default()).await?;
+                printer.print(img, settings.clone()).await?;
+            }
+        }
+        Command::Text {
+            text,
+            scale,
+            center,
+        } => {
+            let text = match text {
+                Some(t) => t,
+                None => {
+                    let mut buf = String::new();
+                    std::io::stdin()
+                        .read_to_string(&mut buf)
+                        .context("failed to read text from stdin")?;
+                    buf
+                }
+            };
+
+            let settings = TextSettings {
+                scale,
+                align: if center { Align::Center } else { Align::Left },
+            };
+
+            printer.print_text(&text, settings).await?;
+        }
     }
 
     Ok(())
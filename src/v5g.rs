@@ -1,14 +1,72 @@
-use std::{fmt, time::Duration};
+use std::{fmt, ops::RangeInclusive, time::Duration};
 
 use btleplug::api::{bleuuid::uuid_from_u16, Characteristic, Peripheral as _, WriteType};
 use log::debug;
 use uuid::Uuid;
 
+use crate::font;
 use crate::printer::PrintDriver;
 
 pub const CHAR_UUID_WRITE_NO_RESP: Uuid = uuid_from_u16(0xae01);
 pub const CHAR_UUID_NOTIFY: Uuid = uuid_from_u16(0xae02);
 
+/// The lattice framing magic is shared across the cat-printer family.
+const LATTICE_START_MAGIC: [u8; 11] = [
+    0xaa, 0x55, 0x17, 0x38, 0x44, 0x5f, 0x5f, 0x5f, 0x44, 0x38, 0x2c,
+];
+const LATTICE_END_MAGIC: [u8; 11] = [
+    0xaa, 0x55, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x17,
+];
+
+/// A cat-printer family member. Selecting the right one picks up its quirks via
+/// [`DeviceProfile::for_model`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    /// The variant this crate was originally reverse-engineered against.
+    Mx10,
+    Mx06,
+    Gb01,
+    Gb02,
+}
+
+/// Per-model quirks carried alongside the [`Driver`]: which characteristics to
+/// talk to, the lattice framing magic, and the usable energy range.
+#[derive(Debug, Clone)]
+pub struct DeviceProfile {
+    pub model: Model,
+    pub char_write: Uuid,
+    pub char_notify: Uuid,
+    pub lattice_start: [u8; 11],
+    pub lattice_end: [u8; 11],
+    pub energy_range: RangeInclusive<u16>,
+}
+
+impl DeviceProfile {
+    pub fn for_model(model: Model) -> Self {
+        // every family member so far exposes the same GATT characteristics and
+        // lattice magic; only the energy range meaningfully differs
+        let energy_range = match model {
+            Model::Mx10 | Model::Mx06 => 0..=0xffff,
+            Model::Gb01 | Model::Gb02 => 0..=0x2ee0,
+        };
+
+        Self {
+            model,
+            char_write: CHAR_UUID_WRITE_NO_RESP,
+            char_notify: CHAR_UUID_NOTIFY,
+            lattice_start: LATTICE_START_MAGIC,
+            lattice_end: LATTICE_END_MAGIC,
+            energy_range,
+        }
+    }
+}
+
+impl Default for DeviceProfile {
+    fn default() -> Self {
+        Self::for_model(Model::Mx10)
+    }
+}
+
 const CRC_TABLE: [u8; 256] = [
     0x00, 0x07, 0x0e, 0x09, 0x1c, 0x1b, 0x12, 0x15, 0x38, 0x3f, 0x36, 0x31, 0x24, 0x23, 0x2a, 0x2d,
     0x70, 0x77, 0x7e, 0x79, 0x6c, 0x6b, 0x62, 0x65, 0x48, 0x4f, 0x46, 0x41, 0x54, 0x53, 0x5a, 0x5d,
@@ -119,22 +177,8 @@ impl CmdPacket {
         Self::new(CommandId::PrintMode, vec![mode as u8])
     }
 
-    pub fn lattice_start() -> Self {
-        Self::new(
-            CommandId::Lattice,
-            vec![
-                0xaa, 0x55, 0x17, 0x38, 0x44, 0x5f, 0x5f, 0x5f, 0x44, 0x38, 0x2c,
-            ],
-        )
-    }
-
-    pub fn lattice_end() -> Self {
-        Self::new(
-            CommandId::Lattice,
-            vec![
-                0xaa, 0x55, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x17,
-            ],
-        )
+    pub fn lattice(magic: [u8; 11]) -> Self {
+        Self::new(CommandId::Lattice, magic.to_vec())
     }
 }
 
@@ -171,9 +215,88 @@ impl std::error::Error for ParseError {
     }
 }
 
+/// Decoded `GetDeviceState` (`0xa3`) payload.
+///
+/// The status block isn't formally documented; the field offsets below follow
+/// what the MX-series heads report — battery percentage and head temperature in
+/// the first two bytes, then a flags byte. Unknown/short payloads decode to
+/// zeroes, and [`raw`](Self::raw) keeps the original bytes around for devices
+/// that lay the block out differently.
+#[derive(Debug, Clone)]
+pub struct DeviceStatus {
+    /// battery charge, percent
+    pub battery: u8,
+    /// print-head temperature, °C
+    pub temperature: u8,
+    pub no_paper: bool,
+    pub cover_open: bool,
+    pub overheated: bool,
+    pub low_power: bool,
+    /// receive buffer is full; the host must hold off sending more data
+    pub buffer_full: bool,
+    /// the undecoded status payload
+    pub raw: Vec<u8>,
+}
+
+impl DeviceStatus {
+    fn decode(data: &[u8]) -> Self {
+        let flags = data.get(2).copied().unwrap_or(0);
+        Self {
+            battery: data.first().copied().unwrap_or(0),
+            temperature: data.get(1).copied().unwrap_or(0),
+            no_paper: flags & 0x01 != 0,
+            cover_open: flags & 0x02 != 0,
+            overheated: flags & 0x04 != 0,
+            low_power: flags & 0x08 != 0,
+            buffer_full: flags & 0x10 != 0,
+            raw: data.to_vec(),
+        }
+    }
+
+    /// Reject a status block that we shouldn't start a job against, so we don't
+    /// silently burn a failed print.
+    ///
+    /// Overheating is deliberately *not* a hard fault: it's transient and the
+    /// transmit loop throttles on it (see [`Driver::send_packets`]) rather than
+    /// aborting.
+    pub fn ensure_printable(&self) -> Result<(), PrintError> {
+        if self.no_paper {
+            return Err(PrintError::OutOfPaper);
+        }
+        if self.cover_open {
+            return Err(PrintError::CoverOpen);
+        }
+        if self.low_power {
+            return Err(PrintError::LowPower);
+        }
+        Ok(())
+    }
+}
+
+/// A media/hardware condition that aborts a print, either before the job
+/// starts or when a mid-stream status poll reports it.
+#[derive(Debug)]
+pub enum PrintError {
+    OutOfPaper,
+    CoverOpen,
+    LowPower,
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfPaper => write!(f, "printer is out of paper"),
+            Self::CoverOpen => write!(f, "printer cover is open"),
+            Self::LowPower => write!(f, "printer battery is too low to print"),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
 #[derive(Debug)]
 pub enum NotifyResponse {
-    DeviceState(Vec<u8>),
+    Status(DeviceStatus),
 }
 
 impl NotifyResponse {
@@ -218,12 +341,95 @@ impl NotifyResponse {
         }
 
         match id {
-            0xa3u8 => Ok(NotifyResponse::DeviceState(data)),
+            0xa3u8 => Ok(NotifyResponse::Status(DeviceStatus::decode(&data))),
             _ => Err(ParseError::UnknownType),
         }
     }
 }
 
+/// Bayer 4×4 ordered-dither threshold matrix, entries in `0..16`.
+const BAYER_4X4: [[u8; 4]; 4] = [
+    [0, 8, 2, 10],
+    [12, 4, 14, 6],
+    [3, 11, 1, 9],
+    [15, 7, 13, 5],
+];
+
+/// Strategy for reducing a grayscale image to the head's 1-bit dot grid.
+#[derive(Debug, Clone, Copy)]
+pub enum Dither {
+    /// Hard `< 127` threshold. Fast, but throws away all tonal detail.
+    Threshold,
+    /// 4×4 ordered (Bayer) dithering.
+    Bayer4x4,
+    /// Floyd-Steinberg error diffusion.
+    FloydSteinberg,
+}
+
+/// Reduce a grayscale image to a row-major grid of burn bits (`true` means burn
+/// the dot) using the requested [`Dither`] strategy.
+fn dither_to_burn<I>(img: &I, dither: Dither) -> Vec<bool>
+where
+    I: image::GenericImageView<Pixel = image::Luma<u8>>,
+{
+    let w = img.width();
+    let h = img.height();
+    let mut burn = vec![false; (w * h) as usize];
+
+    match dither {
+        Dither::Threshold => {
+            for j in 0..h {
+                for i in 0..w {
+                    burn[(j * w + i) as usize] = img.get_pixel(i, j).0[0] < 127;
+                }
+            }
+        }
+        Dither::Bayer4x4 => {
+            for j in 0..h {
+                for i in 0..w {
+                    // scale the 0..16 matrix entry up into the 0..256 luma range
+                    let t =
+                        (BAYER_4X4[(j % 4) as usize][(i % 4) as usize] as u16 * 2 + 1) * 256 / 32;
+                    burn[(j * w + i) as usize] = (img.get_pixel(i, j).0[0] as u16) < t;
+                }
+            }
+        }
+        Dither::FloydSteinberg => {
+            // work in a wider signed buffer so diffused error can go negative /
+            // over 255 without clamping
+            let mut work = vec![0i16; (w * h) as usize];
+            for j in 0..h {
+                for i in 0..w {
+                    work[(j * w + i) as usize] = img.get_pixel(i, j).0[0] as i16;
+                }
+            }
+
+            for j in 0..h {
+                for i in 0..w {
+                    let idx = (j * w + i) as usize;
+                    let old = work[idx];
+                    let new = if old < 128 { 0 } else { 255 };
+                    work[idx] = new;
+                    burn[idx] = new == 0;
+
+                    let err = old - new;
+                    let mut diffuse = |x: i64, y: i64, num: i16| {
+                        if x >= 0 && (x as u32) < w && (y as u32) < h {
+                            work[(y as u32 * w + x as u32) as usize] += err * num / 16;
+                        }
+                    };
+                    diffuse(i as i64 + 1, j as i64, 7);
+                    diffuse(i as i64 - 1, j as i64 + 1, 3);
+                    diffuse(i as i64, j as i64 + 1, 5);
+                    diffuse(i as i64 + 1, j as i64 + 1, 1);
+                }
+            }
+        }
+    }
+
+    burn
+}
+
 #[derive(Debug, Clone)]
 pub struct PrintSettings {
     energy: u16,
@@ -232,8 +438,18 @@ pub struct PrintSettings {
     /// feed after? 0 - no feed
     feeds_after: usize,
     quality: u8,
+    dither: Dither,
+    /// how many bytes may be outstanding before we stop to poll the head
+    max_inflight_bytes: usize,
+    /// how often to interleave a status query while streaming
+    poll_interval: Duration,
 }
 
+/// Default flow-control window: bytes outstanding before polling the head.
+const DEFAULT_MAX_INFLIGHT_BYTES: usize = 2048;
+/// Default cadence for interleaved status queries while streaming.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 impl Default for PrintSettings {
     fn default() -> Self {
         Self {
@@ -242,15 +458,58 @@ impl Default for PrintSettings {
             print_speed: 10,
             feeds_after: 2,
             quality: 5,
+            dither: Dither::Threshold,
+            max_inflight_bytes: DEFAULT_MAX_INFLIGHT_BYTES,
+            poll_interval: DEFAULT_POLL_INTERVAL,
         }
     }
 }
 
+impl PrintSettings {
+    pub fn energy(mut self, energy: u16) -> Self {
+        self.energy = energy;
+        self
+    }
+
+    pub fn print_speed(mut self, print_speed: u8) -> Self {
+        self.print_speed = print_speed;
+        self
+    }
+
+    pub fn feeds_after(mut self, feeds_after: usize) -> Self {
+        self.feeds_after = feeds_after;
+        self
+    }
+
+    pub fn quality(mut self, quality: u8) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    pub fn dither(mut self, dither: Dither) -> Self {
+        self.dither = dither;
+        self
+    }
+
+    pub fn max_inflight_bytes(mut self, max_inflight_bytes: usize) -> Self {
+        self.max_inflight_bytes = max_inflight_bytes;
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct Driver<'a> {
     pub peripheral: &'a btleplug::platform::Peripheral,
     pub char_cmd_no_resp: &'a Characteristic,
     pub char_notify: &'a Characteristic,
+    pub profile: DeviceProfile,
+    /// latest decoded status, fed from the caller's single NOTIFY stream
+    pub status_rx: tokio::sync::watch::Receiver<Option<DeviceStatus>>,
 }
 
 impl<'a> PrintDriver for Driver<'a> {
@@ -261,58 +520,312 @@ impl<'a> PrintDriver for Driver<'a> {
     where
         I: image::GenericImageView<Pixel = image::Luma<u8>>,
     {
-        let pkts = {
-            let mut cmds: Vec<CmdPacket> = vec![];
-
-            cmds.push(CmdPacket::quality(5));
-            cmds.push(CmdPacket::lattice_start());
-
-            // routine eachLinePixToCmdB
-            cmds.push(CmdPacket::energy(10000));
-            cmds.push(CmdPacket::print_mode(PrintMode::Image));
-            cmds.push(CmdPacket::print_speed(10));
-
-            for j in 0..img.height() {
-                let mut row_buf = [0u8; HORIZ_RESOLUTION as usize / 8];
-                for i in 0..img.width() {
-                    row_buf[(i as usize) / 8] >>= 1;
-                    // 1 = burn this dot
-                    row_buf[(i as usize) / 8] |= if img.get_pixel(i, j).0[0] < 127 {
-                        0b10000000
-                    } else {
-                        0
-                    };
-                }
-                cmds.push(CmdPacket::new(CommandId::BitmapData, row_buf.to_vec()));
+        // poll the device and refuse to start a job we can't finish cleanly
+        if let Some(status) = self.query_status().await? {
+            status.ensure_printable()?;
+        }
+
+        // routine eachLinePixToCmdB
+        let width = img.width();
+        let burn = dither_to_burn(&img, settings.dither);
+        let mut body: Vec<CmdPacket> = vec![];
+        for j in 0..img.height() {
+            let mut row_buf = [0u8; HORIZ_RESOLUTION as usize / 8];
+            for i in 0..img.width() {
+                row_buf[(i as usize) / 8] >>= 1;
+                // 1 = burn this dot
+                row_buf[(i as usize) / 8] |= if burn[(j * width + i) as usize] {
+                    0b10000000
+                } else {
+                    0
+                };
             }
+            body.push(CmdPacket::new(CommandId::BitmapData, row_buf.to_vec()));
+        }
+        // end eachLinePixToCmdB
+
+        let pkts = self.frame_job(
+            settings.quality,
+            settings.energy,
+            settings.print_mode,
+            settings.print_speed,
+            settings.feeds_after,
+            body,
+        );
+
+        self.send_packets(pkts, settings.max_inflight_bytes, settings.poll_interval)
+            .await
+    }
+}
 
-            // end eachLinePixToCmdB
+/// Horizontal placement of a rasterized text line within the 384-dot row.
+#[derive(Debug, Clone, Copy)]
+pub enum Align {
+    Left,
+    Center,
+}
 
-            cmds.push(CmdPacket::new(CommandId::Paper, vec![0x30, 0x00]));
-            cmds.push(CmdPacket::new(CommandId::Paper, vec![0x30, 0x00]));
-            cmds.push(CmdPacket::lattice_end());
+#[derive(Debug, Clone)]
+pub struct TextSettings {
+    /// integer pixel scale applied to the 8×16 base glyphs
+    pub scale: u32,
+    pub align: Align,
+}
+
+impl Default for TextSettings {
+    fn default() -> Self {
+        Self {
+            scale: 1,
+            align: Align::Left,
+        }
+    }
+}
+
+/// Look up the glyph for `ch`, falling back to `?` for anything outside the
+/// embedded printable-ASCII range.
+fn glyph_for(ch: char) -> &'static [u8; font::GLYPH_H] {
+    let code = ch as u32;
+    if (0x20..=0x7e).contains(&code) {
+        &font::FONT[(code as u8 - font::FIRST) as usize]
+    } else {
+        &font::FONT[(b'?' - font::FIRST) as usize]
+    }
+}
+
+/// Rasterize UTF-8 `text` into `HORIZ_RESOLUTION`-wide rows using the embedded
+/// bitmap font, wrapping on word then character boundaries and scaling each
+/// glyph by `scale`. Each returned `bool` is one dot (`true` = burn).
+fn render_text(text: &str, scale: u32, align: Align) -> Vec<Vec<bool>> {
+    let scale = scale.max(1);
+    let cell_w = font::GLYPH_W as u32 * scale;
+    let cell_h = font::GLYPH_H as u32 * scale;
+    let cols = (HORIZ_RESOLUTION / cell_w).max(1) as usize;
+
+    // break the text into lines that each fit within `cols` glyphs, honoring
+    // explicit newlines first
+    let mut lines: Vec<String> = vec![];
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let wlen = word.chars().count();
+            // a single over-long word is hard-wrapped on character boundaries
+            if wlen > cols {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                for ch in word.chars() {
+                    if line.chars().count() == cols {
+                        lines.push(std::mem::take(&mut line));
+                    }
+                    line.push(ch);
+                }
+                continue;
+            }
 
-            cmds.push(CmdPacket::new(CommandId::GetDeviceState, vec![0x0])); // this triggers NOTIFY with the device state :)
+            let sep = usize::from(!line.is_empty());
+            if line.chars().count() + sep + wlen > cols {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
 
-            cmds
+    let mut rows = vec![vec![false; HORIZ_RESOLUTION as usize]; lines.len() * cell_h as usize];
+    for (li, line) in lines.iter().enumerate() {
+        let line_w = line.chars().count() as u32 * cell_w;
+        let x0 = match align {
+            Align::Left => 0,
+            Align::Center => HORIZ_RESOLUTION.saturating_sub(line_w) / 2,
         };
+        for (ci, ch) in line.chars().enumerate() {
+            let glyph = glyph_for(ch);
+            let gx = x0 + ci as u32 * cell_w;
+            for (gy, byte) in glyph.iter().enumerate() {
+                for bit in 0..font::GLYPH_W {
+                    if byte & (0x80 >> bit) == 0 {
+                        continue;
+                    }
+                    // expand one source pixel into a scale×scale block
+                    for sy in 0..scale {
+                        for sx in 0..scale {
+                            let px = gx + bit as u32 * scale + sx;
+                            let py = li as u32 * cell_h + gy as u32 * scale + sy;
+                            if px < HORIZ_RESOLUTION {
+                                rows[py as usize][px as usize] = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    rows
+}
 
+/// How long to wait for the printer to answer a pre-print status query before
+/// giving up and printing anyway.
+const STATUS_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Head temperature (°C) at or above which the transmit loop starts throttling
+/// to let the head cool, even before the device asserts its overheat flag.
+const HEAD_TEMP_THROTTLE: u8 = 50;
+
+impl<'a> Driver<'a> {
+    /// Ask the printer for its status block and wait (up to [`STATUS_TIMEOUT`])
+    /// for the matching NOTIFY. Returns `None` if the device never answers, so
+    /// callers can fall back to printing blind rather than hanging.
+    ///
+    /// The NOTIFY is read off [`status_rx`](Self::status_rx) — the single
+    /// notification stream owned by the caller — rather than opening a second
+    /// `notifications()` stream, which on single-consumer backends would race
+    /// the existing reader for the reply.
+    async fn query_status(&self) -> anyhow::Result<Option<DeviceStatus>> {
+        let mut rx = self.status_rx.clone();
+        // mark whatever's already cached as seen *before* writing, so
+        // `changed()` below blocks for the reply to this query rather than
+        // returning an earlier poll's status immediately
+        rx.borrow_and_update();
+
+        let pkt = CmdPacket::new(CommandId::GetDeviceState, vec![0x0]).to_vec()?;
+        self.peripheral
+            .write(self.char_cmd_no_resp, &pkt, WriteType::WithoutResponse)
+            .await?;
+
+        match tokio::time::timeout(STATUS_TIMEOUT, rx.changed()).await {
+            // fresh status arrived on the shared stream
+            Ok(Ok(())) => Ok(rx.borrow().clone()),
+            // timed out, or the sender was dropped
+            _ => Ok(None),
+        }
+    }
+
+    /// Stream a prepared list of command packets over the WRITE_NO_RESP
+    /// characteristic, `TX_SIZE` bytes at a time, using credit-based flow
+    /// control instead of a fixed delay.
+    ///
+    /// We allow up to `max_inflight_bytes` of data out before interleaving a
+    /// `GetDeviceState` query. A full buffer, the overheat flag, or a head
+    /// temperature at/above [`HEAD_TEMP_THROTTLE`] backs the inter-chunk delay
+    /// off (and pauses for a poll interval) so the head can drain and cool;
+    /// otherwise we speed up and refill credits.
+    async fn send_packets(
+        &self,
+        pkts: Vec<CmdPacket>,
+        max_inflight_bytes: usize,
+        poll_interval: Duration,
+    ) -> anyhow::Result<()> {
         let mut buf = Vec::<u8>::new();
         for pkt in pkts.into_iter() {
             buf.append(&mut pkt.to_vec()?);
         }
 
+        let mut credits = max_inflight_bytes as i64;
+        let mut last_poll = tokio::time::Instant::now();
+        // adaptive inter-chunk delay, nudged by the reported headroom
+        let mut delay = Duration::from_millis(4);
+
         for dat in buf.chunks(TX_SIZE) {
             debug!("CMD {:?}", dat);
             self.peripheral
                 .write(self.char_cmd_no_resp, dat, WriteType::WithoutResponse)
                 .await?;
 
-            tokio::time::sleep(Duration::from_secs_f32(0.01)).await;
+            credits -= dat.len() as i64;
+            tokio::time::sleep(delay).await;
+
+            // poll once we've spent our credits or the poll interval elapses
+            if credits <= 0 || last_poll.elapsed() >= poll_interval {
+                last_poll = tokio::time::Instant::now();
+                match self.query_status().await? {
+                    Some(status) => {
+                        // a hard fault mid-stream aborts rather than corrupting
+                        status.ensure_printable()?;
+
+                        let hot = status.temperature >= HEAD_TEMP_THROTTLE;
+                        if status.buffer_full || status.overheated || hot {
+                            // head out of room or running hot: back off and let
+                            // it drain/cool before sending more
+                            delay = (delay * 2)
+                                .clamp(Duration::from_millis(1), Duration::from_millis(40));
+                            tokio::time::sleep(poll_interval).await;
+                            credits = (max_inflight_bytes / 2) as i64;
+                        } else {
+                            // headroom to spare: speed up and refill credits
+                            delay = delay.saturating_sub(Duration::from_millis(1));
+                            credits = max_inflight_bytes as i64;
+                        }
+                    }
+                    // no answer: leave the current pacing as is and refill credits
+                    None => credits = max_inflight_bytes as i64,
+                }
+            }
         }
 
         Ok(())
     }
+
+    /// Wrap a body of `BitmapData` packets in the shared job framing: quality,
+    /// lattice start, energy (clamped to the model's range), print mode, and
+    /// speed, then `feeds_after` paper advances, the lattice end, and a closing
+    /// status query. Used by both [`print`](Self::print) and
+    /// [`print_text`](Self::print_text) so the preamble can't drift apart.
+    fn frame_job(
+        &self,
+        quality: u8,
+        energy: u16,
+        print_mode: PrintMode,
+        print_speed: u8,
+        feeds_after: usize,
+        body: Vec<CmdPacket>,
+    ) -> Vec<CmdPacket> {
+        // clamp energy into the range this model actually honors
+        let range = &self.profile.energy_range;
+        let energy = energy.clamp(*range.start(), *range.end());
+
+        let mut cmds: Vec<CmdPacket> = vec![];
+        cmds.push(CmdPacket::quality(quality));
+        cmds.push(CmdPacket::lattice(self.profile.lattice_start));
+        cmds.push(CmdPacket::energy(energy));
+        cmds.push(CmdPacket::print_mode(print_mode));
+        cmds.push(CmdPacket::print_speed(print_speed));
+
+        cmds.extend(body);
+
+        for _ in 0..feeds_after {
+            cmds.push(CmdPacket::new(CommandId::Paper, vec![0x30, 0x00]));
+        }
+        cmds.push(CmdPacket::lattice(self.profile.lattice_end));
+        cmds.push(CmdPacket::new(CommandId::GetDeviceState, vec![0x0])); // this triggers NOTIFY with the device state :)
+
+        cmds
+    }
+
+    /// Rasterize `text` with the embedded bitmap font and print it using
+    /// [`PrintMode::Text`].
+    pub async fn print_text(&self, text: &str, settings: TextSettings) -> anyhow::Result<()> {
+        let rows = render_text(text, settings.scale, settings.align);
+
+        let mut body: Vec<CmdPacket> = vec![];
+        for row in &rows {
+            let mut row_buf = [0u8; HORIZ_RESOLUTION as usize / 8];
+            for i in 0..HORIZ_RESOLUTION as usize {
+                row_buf[i / 8] >>= 1;
+                // 1 = burn this dot
+                row_buf[i / 8] |= if row[i] { 0b10000000 } else { 0 };
+            }
+            body.push(CmdPacket::new(CommandId::BitmapData, row_buf.to_vec()));
+        }
+
+        let pkts = self.frame_job(5, 10_000, PrintMode::Text, 10, 2, body);
+
+        self.send_packets(pkts, DEFAULT_MAX_INFLIGHT_BYTES, DEFAULT_POLL_INTERVAL)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -344,6 +857,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_device_profile_energy_ranges() {
+        // the GB-series printers cap energy far below the MX heads
+        assert_eq!(*DeviceProfile::for_model(Model::Mx10).energy_range.end(), 0xffff);
+        assert_eq!(*DeviceProfile::for_model(Model::Gb01).energy_range.end(), 0x2ee0);
+    }
+
+    #[test]
+    fn test_device_status_flags() {
+        // battery 90%, head 41°C, no_paper + overheated set (0x01 | 0x04)
+        let status = DeviceStatus::decode(&[90, 41, 0x05]);
+        assert_eq!(status.battery, 90);
+        assert_eq!(status.temperature, 41);
+        assert!(status.no_paper);
+        assert!(!status.cover_open);
+        assert!(status.overheated);
+        assert!(matches!(
+            status.ensure_printable(),
+            Err(PrintError::OutOfPaper)
+        ));
+
+        // a clean block prints fine
+        assert!(DeviceStatus::decode(&[100, 30, 0x00])
+            .ensure_printable()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_dither_threshold() {
+        // pixels straddling the `< 127` cutoff
+        let img = image::GrayImage::from_raw(4, 1, vec![0, 126, 127, 255]).unwrap();
+        let burn = dither_to_burn(&img, Dither::Threshold);
+        assert_eq!(burn, vec![true, true, false, false]);
+    }
+
+    #[test]
+    fn test_dither_floyd_steinberg_preserves_black_and_white() {
+        // a solid-black and a solid-white image must dither to all/no burn
+        let black = image::GrayImage::from_raw(8, 8, vec![0; 64]).unwrap();
+        let white = image::GrayImage::from_raw(8, 8, vec![255; 64]).unwrap();
+        assert!(dither_to_burn(&black, Dither::FloydSteinberg)
+            .iter()
+            .all(|&b| b));
+        assert!(dither_to_burn(&white, Dither::FloydSteinberg)
+            .iter()
+            .all(|&b| !b));
+    }
+
+    #[test]
+    fn test_render_text_wraps_at_width() {
+        // at scale 1 a row fits 384/8 = 48 glyphs; 60 'x' must spill to 2 lines
+        let rows = render_text(&"x".repeat(60), 1, Align::Left);
+        assert_eq!(rows.len(), 2 * font::GLYPH_H);
+        // every row is exactly the head width
+        assert!(rows.iter().all(|r| r.len() == HORIZ_RESOLUTION as usize));
+    }
+
+    #[test]
+    fn test_render_text_center_offsets() {
+        // a single centered glyph should leave its left margin blank
+        let left = render_text("x", 1, Align::Left);
+        let center = render_text("x", 1, Align::Center);
+        assert!(left[0][0..font::GLYPH_W].iter().any(|&b| b) || left.len() == font::GLYPH_H);
+        // the centered line should not start burning in the first column
+        assert!(center.iter().all(|r| !r[0]));
+    }
+
     #[test]
     fn test_print_mode() {
         let pkt = CmdPacket::print_mode(PrintMode::Image);